@@ -0,0 +1,87 @@
+/// How the snake interacts with the edge of the board.
+#[derive(Clone, Copy, PartialEq)]
+pub enum WallMode {
+    /// Running into the edge ends the game.
+    Walls,
+    /// Running off one edge re-enters on the opposite edge.
+    Wrap,
+}
+
+/// Runtime-tunable game parameters, populated from command-line arguments
+/// so players can change difficulty and board size without recompiling.
+#[derive(Clone)]
+pub struct Config {
+    pub game_size: usize,
+    pub square_size: usize,
+    pub initial_period: u64,
+    pub period_change: f32,
+    pub wall_mode: WallMode,
+    pub tui: bool,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            game_size: 20,
+            square_size: 20,
+            initial_period: 300,
+            period_change: 0.95,
+            wall_mode: WallMode::Walls,
+            tui: false,
+        }
+    }
+}
+
+impl Config {
+    /// Parses `--size`, `--cell`, `--speed`, `--accel`, `--mode` and `--tui`
+    /// out of the process's command-line arguments, falling back to sensible
+    /// defaults.
+    pub fn from_args() -> Config {
+        let mut config = Config::default();
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--size" => config.game_size = parse_nonzero(&mut args, "--size"),
+                "--cell" => config.square_size = parse_nonzero(&mut args, "--cell"),
+                "--speed" => config.initial_period = parse_value(&mut args, "--speed"),
+                "--accel" => config.period_change = parse_value(&mut args, "--accel"),
+                "--mode" => config.wall_mode = parse_wall_mode(&mut args),
+                "--tui" => config.tui = true,
+                _ => {}
+            }
+        }
+        config
+    }
+}
+
+fn parse_value<T: std::str::FromStr>(args: &mut impl Iterator<Item = String>, flag: &str) -> T {
+    let value = args
+        .next()
+        .unwrap_or_else(|| panic!("{flag} requires a value"));
+    value
+        .parse()
+        .unwrap_or_else(|_| panic!("invalid value for {flag}: {value}"))
+}
+
+/// Like `parse_value`, but rejects zero: a `game_size` of 0 leaves no valid
+/// board cells, which otherwise panics deep inside `rand` with an opaque
+/// "cannot sample empty range" instead of a clear message here, and a
+/// `square_size` of 0 would draw every cell zero pixels wide.
+fn parse_nonzero(args: &mut impl Iterator<Item = String>, flag: &str) -> usize {
+    let value: usize = parse_value(args, flag);
+    if value == 0 {
+        panic!("invalid value for {flag}: 0 (must be greater than 0)");
+    }
+    value
+}
+
+fn parse_wall_mode(args: &mut impl Iterator<Item = String>) -> WallMode {
+    let value = args
+        .next()
+        .unwrap_or_else(|| panic!("--mode requires a value"));
+    match value.as_str() {
+        "walls" => WallMode::Walls,
+        "wrap" => WallMode::Wrap,
+        _ => panic!("invalid value for --mode: {value} (expected \"walls\" or \"wrap\")"),
+    }
+}
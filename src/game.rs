@@ -0,0 +1,220 @@
+use rand::prelude::*;
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use crate::config::{Config, WallMode};
+
+#[derive(Clone, PartialEq)]
+pub struct GamePoint {
+    pub x: usize,
+    pub y: usize,
+}
+
+#[derive(Clone, PartialEq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn reverses(&self, other: &Direction) -> bool {
+        matches!(
+            (self, other),
+            (Direction::Up, Direction::Down)
+                | (Direction::Down, Direction::Up)
+                | (Direction::Left, Direction::Right)
+                | (Direction::Right, Direction::Left)
+        )
+    }
+}
+
+/// How many direction changes `Snake::queue_direction` will hold before
+/// dropping further presses, so a burst of keys can't grow unbounded.
+const INPUT_BUFFER_SIZE: usize = 2;
+
+impl GamePoint {
+    pub fn random(game_size: usize) -> GamePoint {
+        let mut rng = rand::thread_rng();
+        GamePoint {
+            x: rng.gen_range(0..game_size),
+            y: rng.gen_range(0..game_size),
+        }
+    }
+    pub fn to_pixel(&self, square_size: usize) -> (i32, i32) {
+        let x: i32 = (self.x * square_size).try_into().unwrap();
+        let y: i32 = (self.y * square_size).try_into().unwrap();
+        (x, y)
+    }
+    pub fn matches(&self, other: &GamePoint) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+pub struct Food {
+    pub position: GamePoint,
+}
+
+impl Food {
+    /// Spawns on a uniformly random free cell, guaranteed not to land on the
+    /// snake's starting body.
+    pub fn new(snake: &Snake, game_size: usize) -> Food {
+        let position = Self::random_free_cell(snake, game_size)
+            .expect("the board has no free cell for food beside the snake");
+        Food { position }
+    }
+    /// Moves the food to a uniformly random free cell. Returns `false` if the
+    /// snake already fills the whole board, meaning there's nowhere left to go.
+    pub fn mov(&mut self, snake: &Snake, game_size: usize) -> bool {
+        match Self::random_free_cell(snake, game_size) {
+            Some(point) => {
+                self.position = point;
+                true
+            }
+            None => false,
+        }
+    }
+    fn random_free_cell(snake: &Snake, game_size: usize) -> Option<GamePoint> {
+        let free_cells: Vec<GamePoint> = (0..game_size)
+            .flat_map(|x| (0..game_size).map(move |y| GamePoint { x, y }))
+            .filter(|point| !snake.body.iter().any(|segment| segment.matches(point)))
+            .collect();
+        free_cells.choose(&mut rand::thread_rng()).cloned()
+    }
+}
+
+pub struct Snake {
+    pub body: VecDeque<GamePoint>,
+    pub direction: Direction,
+    pending: VecDeque<Direction>,
+}
+
+impl Snake {
+    pub fn new(game_size: usize) -> Snake {
+        Snake {
+            body: VecDeque::from([GamePoint::random(game_size)]),
+            direction: Direction::Up,
+            pending: VecDeque::new(),
+        }
+    }
+    pub fn get_head(&self) -> &GamePoint {
+        self.body.front().unwrap()
+    }
+    /// Buffers a direction change for the next tick(s), rather than writing
+    /// `self.direction` straight away. Ignores a direction that's already the
+    /// last one queued (so a direction key held across several polled frames
+    /// doesn't fill the buffer with duplicates and crowd out the next turn),
+    /// rejects one that would reverse the last *queued* (not just current)
+    /// direction, and drops presses once the buffer is full.
+    pub fn queue_direction(&mut self, direction: Direction) {
+        let last = self.pending.back().unwrap_or(&self.direction);
+        if *last == direction {
+            return;
+        }
+        if last.reverses(&direction) {
+            return;
+        }
+        if self.pending.len() >= INPUT_BUFFER_SIZE {
+            return;
+        }
+        self.pending.push_back(direction);
+    }
+    pub fn mov(&mut self, food: &mut Food, config: &Config) -> MoveOutcome {
+        if let Some(next) = self.pending.pop_front() {
+            self.direction = next;
+        }
+        let mut new_head = self.get_head().clone();
+        let limits = config.game_size - 1;
+        // In `Wrap` mode, running off an edge re-enters on the opposite one;
+        // in `Walls` mode it's instant death.
+        match self.direction {
+            Direction::Up if new_head.y == 0 => match config.wall_mode {
+                WallMode::Walls => return MoveOutcome::Crashed,
+                WallMode::Wrap => new_head.y = limits,
+            },
+            Direction::Up => new_head.y -= 1,
+            Direction::Down if new_head.y == limits => match config.wall_mode {
+                WallMode::Walls => return MoveOutcome::Crashed,
+                WallMode::Wrap => new_head.y = 0,
+            },
+            Direction::Down => new_head.y += 1,
+            Direction::Left if new_head.x == 0 => match config.wall_mode {
+                WallMode::Walls => return MoveOutcome::Crashed,
+                WallMode::Wrap => new_head.x = limits,
+            },
+            Direction::Left => new_head.x -= 1,
+            Direction::Right if new_head.x == limits => match config.wall_mode {
+                WallMode::Walls => return MoveOutcome::Crashed,
+                WallMode::Wrap => new_head.x = 0,
+            },
+            Direction::Right => new_head.x += 1,
+        }
+
+        // Check collision.
+        let collisions = self
+            .body
+            .iter()
+            .filter(|x| x.matches(&new_head))
+            .collect::<Vec<&GamePoint>>();
+        if !collisions.is_empty() {
+            return MoveOutcome::Crashed;
+        }
+
+        self.body.push_front(new_head.clone());
+        let eaten = self.is_on_food(food);
+        if eaten {
+            if !food.mov(self, config.game_size) {
+                return MoveOutcome::Won;
+            }
+        } else {
+            self.body.pop_back();
+        }
+        MoveOutcome::Moved { ate: eaten }
+    }
+    pub fn is_on_food(&self, food: &Food) -> bool {
+        self.get_head().matches(&food.position)
+    }
+}
+
+/// The result of advancing the snake by one tick.
+pub enum MoveOutcome {
+    Moved { ate: bool },
+    Crashed,
+    Won,
+}
+
+/// Which screen/interaction mode the game is currently in.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Phase {
+    Playing,
+    Paused,
+    GameOver,
+    Won,
+}
+
+pub struct GameState {
+    pub snake: Snake,
+    pub food: Food,
+    pub time: Instant,
+    pub period: Duration,
+    pub phase: Phase,
+    pub config: Config,
+}
+
+impl GameState {
+    pub fn new(config: Config) -> GameState {
+        let snake = Snake::new(config.game_size);
+        let food = Food::new(&snake, config.game_size);
+        GameState {
+            phase: Phase::Playing,
+            snake,
+            food,
+            time: Instant::now(),
+            period: Duration::from_millis(config.initial_period),
+            config,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+mod raylib_backend;
+mod terminal_backend;
+
+pub use raylib_backend::RaylibRenderer;
+pub use terminal_backend::TerminalRenderer;
+
+use crate::game::GamePoint;
+use crate::Direction;
+
+/// A window-library-agnostic color. Values match the raylib named colors
+/// the game used to draw with, so both backends render the same palette.
+#[derive(Clone, Copy)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const WHITE: Color = Color {
+        r: 255,
+        g: 255,
+        b: 255,
+    };
+    pub const BLACK: Color = Color { r: 0, g: 0, b: 0 };
+    pub const RED: Color = Color {
+        r: 230,
+        g: 41,
+        b: 55,
+    };
+    pub const ROYAL_BLUE: Color = Color {
+        r: 65,
+        g: 105,
+        b: 225,
+    };
+}
+
+/// A single user action read from the backend for this tick.
+pub enum InputEvent {
+    Direction(Direction),
+    TogglePause,
+    Restart,
+    Quit,
+}
+
+/// Everything the core game loop needs from a display backend. `GameState`
+/// and `run_loop` talk to this trait only, so the game itself has no idea
+/// whether it's drawn with raylib or in a terminal.
+pub trait Renderer {
+    fn clear(&mut self);
+    fn draw_cell(&mut self, point: &GamePoint, color: Color);
+    fn draw_text(&mut self, x: i32, y: i32, text: &str);
+    fn present(&mut self);
+    fn poll_input(&mut self) -> Option<InputEvent>;
+    fn should_quit(&mut self) -> bool;
+    /// Frames presented per second, for the HUD readout.
+    fn fps(&self) -> u32;
+}
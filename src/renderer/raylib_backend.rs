@@ -0,0 +1,133 @@
+use raylib::prelude::*;
+
+use super::{Color, InputEvent, Renderer};
+use crate::game::GamePoint;
+use crate::Direction;
+
+const FONT_SIZE: i32 = 20;
+
+/// Vertical gap between successive HUD text rows, matching the y-offsets
+/// `main.rs` draws at (12, 30, 48).
+const HUD_LINE_HEIGHT: i32 = 18;
+
+/// The most HUD lines drawn above the playfield at once: `draw_paused` and
+/// `draw_game_over` each draw three (e.g. FPS, Score, and a status line).
+const HUD_LINES: i32 = 3;
+
+fn to_raylib_color(color: Color) -> raylib::color::Color {
+    raylib::color::Color::new(color.r, color.g, color.b, 255)
+}
+
+enum DrawCommand {
+    Rect(i32, i32, i32, i32, Color),
+    Text(i32, i32, String),
+}
+
+/// The original backend. Drawing calls are buffered into `commands` and
+/// replayed inside a single `begin_drawing`/`end_drawing` pair in `present`,
+/// since raylib-rs only lets one `RaylibDrawHandle` borrow `rl` at a time.
+pub struct RaylibRenderer {
+    rl: RaylibHandle,
+    thread: RaylibThread,
+    commands: Vec<DrawCommand>,
+    square_size: usize,
+    board_size: i32,
+    /// Pixel rows reserved at the top of the window for the score text,
+    /// above the playfield border.
+    top_margin: i32,
+    quit: bool,
+}
+
+impl RaylibRenderer {
+    pub fn new(game_size: usize, square_size: usize) -> RaylibRenderer {
+        let board_size: i32 = (game_size * square_size).try_into().unwrap();
+        // Sized off the HUD text itself (drawn starting at y=12, one row
+        // every HUD_LINE_HEIGHT, up to HUD_LINES rows), not the cell size,
+        // so small `--cell` values don't leave the border overlapping it:
+        // 12px start + the rows below the first + FONT_SIZE for the last
+        // row's height + 8px of padding.
+        let top_margin = 12 + (HUD_LINES - 1) * HUD_LINE_HEIGHT + FONT_SIZE + 8;
+        let (mut rl, thread) = raylib::init()
+            .size(board_size, board_size + top_margin)
+            .title("Hello, Vito")
+            .build();
+        rl.set_target_fps(60);
+        RaylibRenderer {
+            rl,
+            thread,
+            commands: Vec::new(),
+            square_size,
+            board_size,
+            top_margin,
+            quit: false,
+        }
+    }
+}
+
+impl Renderer for RaylibRenderer {
+    fn clear(&mut self) {
+        self.commands.clear();
+    }
+
+    fn draw_cell(&mut self, point: &GamePoint, color: Color) {
+        let (x, y) = point.to_pixel(self.square_size);
+        let size = self.square_size as i32;
+        self.commands
+            .push(DrawCommand::Rect(x, y + self.top_margin, size, size, color));
+    }
+
+    fn draw_text(&mut self, x: i32, y: i32, text: &str) {
+        self.commands.push(DrawCommand::Text(x, y, text.to_owned()));
+    }
+
+    fn present(&mut self) {
+        let mut d = self.rl.begin_drawing(&self.thread);
+        d.clear_background(raylib::color::Color::WHITE);
+        d.draw_rectangle_lines(
+            0,
+            self.top_margin,
+            self.board_size,
+            self.board_size,
+            raylib::color::Color::BLACK,
+        );
+        for command in self.commands.drain(..) {
+            match command {
+                DrawCommand::Rect(x, y, w, h, color) => {
+                    d.draw_rectangle(x, y, w, h, to_raylib_color(color));
+                }
+                DrawCommand::Text(x, y, text) => {
+                    d.draw_text(&text, x, y, FONT_SIZE, raylib::color::Color::BLACK);
+                }
+            }
+        }
+    }
+
+    fn poll_input(&mut self) -> Option<InputEvent> {
+        if self.rl.is_key_down(KeyboardKey::KEY_DOWN) {
+            Some(InputEvent::Direction(Direction::Down))
+        } else if self.rl.is_key_down(KeyboardKey::KEY_UP) {
+            Some(InputEvent::Direction(Direction::Up))
+        } else if self.rl.is_key_down(KeyboardKey::KEY_LEFT) {
+            Some(InputEvent::Direction(Direction::Left))
+        } else if self.rl.is_key_down(KeyboardKey::KEY_RIGHT) {
+            Some(InputEvent::Direction(Direction::Right))
+        } else if self.rl.is_key_pressed(KeyboardKey::KEY_SPACE) {
+            Some(InputEvent::TogglePause)
+        } else if self.rl.is_key_pressed(KeyboardKey::KEY_R) {
+            Some(InputEvent::Restart)
+        } else if self.rl.is_key_pressed(KeyboardKey::KEY_ESCAPE) {
+            self.quit = true;
+            Some(InputEvent::Quit)
+        } else {
+            None
+        }
+    }
+
+    fn should_quit(&mut self) -> bool {
+        self.rl.window_should_close() || self.quit
+    }
+
+    fn fps(&self) -> u32 {
+        self.rl.get_fps() as u32
+    }
+}
@@ -0,0 +1,181 @@
+use std::io::{self, Stdout};
+use std::time::{Duration, Instant};
+
+use crossterm::event::{self, Event, KeyCode};
+use crossterm::execute;
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use tui::backend::CrosstermBackend;
+use tui::layout::{Constraint, Direction as LayoutDirection, Layout};
+use tui::style::Color as TuiColor;
+use tui::widgets::canvas::{Canvas, Rectangle};
+use tui::widgets::{Block, Borders, Paragraph};
+use tui::Terminal;
+
+use super::{Color, InputEvent, Renderer};
+use crate::game::GamePoint;
+use crate::Direction;
+
+fn to_tui_color(color: Color) -> TuiColor {
+    TuiColor::Rgb(color.r, color.g, color.b)
+}
+
+/// Caps the terminal backend at roughly the same 60fps the raylib backend
+/// gets from `set_target_fps`, so `--tui` doesn't spin a core at 100% redrawing
+/// as fast as the CPU allows.
+const TARGET_FRAME: Duration = Duration::from_millis(16);
+
+enum DrawCommand {
+    Cell(GamePoint, Color),
+    // No x/y here: HUD text is laid out in draw order (see `present`): one
+    // joined row above the board, or one line per text when no cells are
+    // drawn, so pixel coordinates from `Renderer::draw_text` don't map to
+    // anything in this backend.
+    Text(String),
+}
+
+/// Renders each `GamePoint` as a colored block inside a bordered `tui` canvas,
+/// so the game can be played over SSH with no raylib window at all. A single
+/// row above the canvas is reserved for HUD text so it never overlaps the
+/// playfield.
+pub struct TerminalRenderer {
+    terminal: Terminal<CrosstermBackend<Stdout>>,
+    commands: Vec<DrawCommand>,
+    quit: bool,
+    game_size: usize,
+    square_size: usize,
+    // `tui` has no built-in frame-rate counter, so `present` times its own
+    // calls to give the HUD something to report.
+    last_present: Instant,
+    fps: u32,
+}
+
+impl TerminalRenderer {
+    pub fn new(game_size: usize, square_size: usize) -> io::Result<TerminalRenderer> {
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let terminal = Terminal::new(CrosstermBackend::new(stdout))?;
+        Ok(TerminalRenderer {
+            terminal,
+            commands: Vec::new(),
+            quit: false,
+            game_size,
+            square_size,
+            last_present: Instant::now(),
+            fps: 0,
+        })
+    }
+}
+
+impl Drop for TerminalRenderer {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+        let _ = execute!(self.terminal.backend_mut(), LeaveAlternateScreen);
+    }
+}
+
+impl Renderer for TerminalRenderer {
+    fn clear(&mut self) {
+        self.commands.clear();
+    }
+
+    fn draw_cell(&mut self, point: &GamePoint, color: Color) {
+        self.commands.push(DrawCommand::Cell(point.clone(), color));
+    }
+
+    fn draw_text(&mut self, _x: i32, _y: i32, text: &str) {
+        self.commands.push(DrawCommand::Text(text.to_owned()));
+    }
+
+    fn present(&mut self) {
+        let since_last = self.last_present.elapsed();
+        if since_last < TARGET_FRAME {
+            std::thread::sleep(TARGET_FRAME - since_last);
+        }
+        let elapsed = self.last_present.elapsed();
+        self.last_present = Instant::now();
+        if elapsed.as_secs_f64() > 0.0 {
+            self.fps = (1.0 / elapsed.as_secs_f64()).round() as u32;
+        }
+
+        let board_size = (self.game_size * self.square_size) as f64;
+        let square_size = self.square_size as f64;
+        let commands = std::mem::take(&mut self.commands);
+        let has_board = commands.iter().any(|c| matches!(c, DrawCommand::Cell(..)));
+        let texts: Vec<&str> = commands
+            .iter()
+            .filter_map(|c| match c {
+                DrawCommand::Text(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect();
+
+        let _ = self.terminal.draw(|frame| {
+            let area = frame.size();
+            if !has_board {
+                let paragraph = Paragraph::new(texts.join("\n"));
+                frame.render_widget(paragraph, area);
+                return;
+            }
+
+            let chunks = Layout::default()
+                .direction(LayoutDirection::Vertical)
+                .constraints([Constraint::Length(1), Constraint::Min(0)])
+                .split(area);
+            frame.render_widget(Paragraph::new(texts.join("  ")), chunks[0]);
+
+            let canvas = Canvas::default()
+                .block(Block::default().borders(Borders::ALL).title("snake"))
+                .x_bounds([0.0, board_size])
+                .y_bounds([0.0, board_size])
+                .paint(|ctx| {
+                    for command in &commands {
+                        if let DrawCommand::Cell(point, color) = command {
+                            let (x, y) = point.to_pixel(square_size as usize);
+                            ctx.draw(&Rectangle {
+                                x: x as f64,
+                                // tui's canvas y grows upward; the board's grows downward.
+                                y: board_size - y as f64 - square_size,
+                                width: square_size,
+                                height: square_size,
+                                color: to_tui_color(*color),
+                            });
+                        }
+                    }
+                });
+            frame.render_widget(canvas, chunks[1]);
+        });
+    }
+
+    fn poll_input(&mut self) -> Option<InputEvent> {
+        if !event::poll(Duration::from_millis(0)).unwrap_or(false) {
+            return None;
+        }
+        match event::read().ok()? {
+            Event::Key(key) => match key.code {
+                KeyCode::Up => Some(InputEvent::Direction(Direction::Up)),
+                KeyCode::Down => Some(InputEvent::Direction(Direction::Down)),
+                KeyCode::Left => Some(InputEvent::Direction(Direction::Left)),
+                KeyCode::Right => Some(InputEvent::Direction(Direction::Right)),
+                KeyCode::Char(' ') => Some(InputEvent::TogglePause),
+                KeyCode::Char('r') | KeyCode::Char('R') => Some(InputEvent::Restart),
+                KeyCode::Esc => {
+                    self.quit = true;
+                    Some(InputEvent::Quit)
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn should_quit(&mut self) -> bool {
+        self.quit
+    }
+
+    fn fps(&self) -> u32 {
+        self.fps
+    }
+}